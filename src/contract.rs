@@ -1,16 +1,20 @@
 use cosmwasm_std::{
     entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
-    Addr, Timestamp, Decimal, StdError, to_json_binary
+    Addr, Timestamp, Decimal, StdError, Storage, to_json_binary
 };
 use cw2::set_contract_version;
 use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse, Cw20QueryMsg, BalanceResponse, TokenInfoResponse};
 use cw_controllers::Admin;
+use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 const CONTRACT_NAME: &str = "crates.io:custom-token";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_HISTORY_LIMIT: u32 = 10;
+const MAX_HISTORY_LIMIT: u32 = 30;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct InstantiateMsg {
     pub name: String,
@@ -19,6 +23,9 @@ pub struct InstantiateMsg {
     pub initial_supply: Uint128,
     pub fee_receiver: String,
     pub owner: Option<String>,
+    /// A factory contract trusted to mint directly to a `recipient` on a caller's behalf (see
+    /// `ExecuteMsg::Mint`). `None` disables factory-relayed minting entirely.
+    pub factory: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -27,10 +34,18 @@ pub enum ExecuteMsg {
     Transfer { recipient: String, amount: Uint128 },
     Burn { amount: Uint128 },
     Send { contract: String, amount: Uint128, msg: Binary },
-    Mint {},
+    /// `recipient` may only be set by the configured `Config::factory`, which relays mints to
+    /// the address that actually called it rather than crediting itself.
+    Mint { recipient: Option<String> },
     IncreaseAllowance { spender: String, amount: Uint128, expires: Option<u64> },
     DecreaseAllowance { spender: String, amount: Uint128, expires: Option<u64> },
     TransferFrom { owner: String, recipient: String, amount: Uint128 },
+    BurnFrom { owner: String, amount: Uint128 },
+    SendFrom { owner: String, contract: String, amount: Uint128, msg: Binary },
+    AddMinter { address: String, cap: Option<Uint128> },
+    RemoveMinter { address: String },
+    MintTo { recipient: String, amount: Uint128 },
+    SetMintCap { amount: Option<Uint128> },
     SetMintAmount { amount: Uint128 },
     SetMintEnabled { enabled: bool },
     LockOwnership {},
@@ -47,6 +62,11 @@ pub enum QueryMsg {
     GetConfig {},
     GetMintInfo { address: String },
     GetTotalBurned {},
+    TransactionHistory {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -58,11 +78,27 @@ pub struct Config {
     pub mint_enabled: bool,
     pub mint_amount: Uint128,
     pub owner: Addr,
+    /// Ceiling on `TOKEN_INFO.total_supply` enforced by both the faucet and `MintTo`. `None`
+    /// leaves supply uncapped.
+    pub max_supply: Option<Uint128>,
+    /// Lifetime ceiling on how much a single address may claim from the faucet, in base units
+    /// (so it scales with `decimals`). `None` leaves claims unbounded.
+    pub per_address_mint_cap: Option<Uint128>,
+    /// The only address allowed to pass `recipient` to `ExecuteMsg::Mint`, letting a trusted
+    /// factory relay a mint to the caller that actually invoked it.
+    pub factory: Option<Addr>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct MintInfo {
     pub last_mint_time: Timestamp,
+    pub total_minted: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct MinterData {
+    pub cap: Option<Uint128>,
+    pub minted: Uint128,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -70,6 +106,38 @@ pub struct TotalBurnedResponse {
     pub total_burned: Uint128,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MintInfoResponse {
+    pub last_mint_time: Timestamp,
+    pub total_minted: Uint128,
+    /// `None` when `Config::per_address_mint_cap` is unset (unbounded claims).
+    pub remaining_mint_allowance: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TxKind {
+    Transfer,
+    TransferFrom,
+    Mint,
+    Burn,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Tx {
+    pub kind: TxKind,
+    pub from: Addr,
+    pub to: Addr,
+    pub amount: Uint128,
+    pub fee: Uint128,
+    pub block_time: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TransactionHistoryResponse {
+    pub txs: Vec<Tx>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TokenInfo {
     pub name: String,
@@ -93,6 +161,7 @@ pub fn instantiate(
     };
 
     let fee_receiver = deps.api.addr_validate(&msg.fee_receiver)?;
+    let factory = msg.factory.map(|f| deps.api.addr_validate(&f)).transpose()?;
 
     let config = Config {
         mint_interval_seconds: 24 * 60 * 60,
@@ -102,6 +171,9 @@ pub fn instantiate(
         mint_enabled: false,
         mint_amount: Uint128::from(400_000_000_000_000_000u128),
         owner: owner.clone(),
+        max_supply: None,
+        per_address_mint_cap: None,
+        factory,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -133,41 +205,66 @@ pub fn execute(
     match msg {
         ExecuteMsg::Transfer { recipient, amount } => execute_transfer(deps, env, info, recipient, amount),
         ExecuteMsg::Burn { amount } => execute_burn(deps, env, info, amount),
-        ExecuteMsg::Mint {} => execute_mint(deps, env, info),
+        ExecuteMsg::Send { contract, amount, msg } => execute_send(deps, env, info, contract, amount, msg),
+        ExecuteMsg::Mint { recipient } => execute_mint(deps, env, info, recipient),
+        ExecuteMsg::IncreaseAllowance { spender, amount, expires } => {
+            execute_increase_allowance(deps, env, info, spender, amount, expires)
+        }
+        ExecuteMsg::DecreaseAllowance { spender, amount, expires } => {
+            execute_decrease_allowance(deps, env, info, spender, amount, expires)
+        }
+        ExecuteMsg::SetMintCap { amount } => execute_set_mint_cap(deps, info, amount),
         ExecuteMsg::SetMintAmount { amount } => execute_set_mint_amount(deps, env, info, amount),
         ExecuteMsg::SetMintEnabled { enabled } => execute_set_mint_enabled(deps, env, info, enabled),
         ExecuteMsg::LockOwnership {} => execute_lock_ownership(deps, env, info),
         ExecuteMsg::TransferOwnership { new_owner } => execute_transfer_ownership(deps, env, info, new_owner),
         ExecuteMsg::TransferFrom { owner, recipient, amount } => execute_transfer_from(deps, env, info, owner, recipient, amount),
-        _ => Err(StdError::generic_err("Unsupported execute message")),
+        ExecuteMsg::BurnFrom { owner, amount } => execute_burn_from(deps, env, info, owner, amount),
+        ExecuteMsg::SendFrom { owner, contract, amount, msg } => {
+            execute_send_from(deps, env, info, owner, contract, amount, msg)
+        }
+        ExecuteMsg::AddMinter { address, cap } => execute_add_minter(deps, info, address, cap),
+        ExecuteMsg::RemoveMinter { address } => execute_remove_minter(deps, info, address),
+        ExecuteMsg::MintTo { recipient, amount } => execute_mint_to(deps, env, info, recipient, amount),
     }
 }
 
 fn execute_transfer(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     amount: Uint128,
 ) -> Result<Response, StdError> {
     let config = CONFIG.load(deps.storage)?;
     let recipient_addr = deps.api.addr_validate(&recipient)?;
-    
+
     let fee = amount * config.fee_percent;
     let amount_after_fee = amount.checked_sub(fee)?;
 
     BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_sub(amount)
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
     })?;
 
     BALANCES.update(deps.storage, &recipient_addr, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_add(amount_after_fee)
+        Ok(balance.unwrap_or_default().checked_add(amount_after_fee)?)
     })?;
 
     BALANCES.update(deps.storage, &config.fee_receiver, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_add(fee)
+        Ok(balance.unwrap_or_default().checked_add(fee)?)
     })?;
 
+    let tx = Tx {
+        kind: TxKind::Transfer,
+        from: info.sender.clone(),
+        to: recipient_addr.clone(),
+        amount: amount_after_fee,
+        fee,
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &info.sender, &tx)?;
+    record_tx(deps.storage, &recipient_addr, &tx)?;
+
     Ok(Response::new()
         .add_attribute("action", "transfer")
         .add_attribute("from", info.sender)
@@ -178,7 +275,7 @@ fn execute_transfer(
 
 fn execute_transfer_from(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     owner: String,
     recipient: String,
@@ -187,7 +284,10 @@ fn execute_transfer_from(
     let config = CONFIG.load(deps.storage)?;
     let owner_addr = deps.api.addr_validate(&owner)?;
     let recipient_addr = deps.api.addr_validate(&recipient)?;
-    
+
+    let allowance = ALLOWANCES.load(deps.storage, (&owner_addr, &info.sender))?;
+    assert_not_expired(&allowance, &env)?;
+
     let fee = amount * config.fee_percent;
     let amount_after_fee = amount.checked_sub(fee)?;
 
@@ -198,17 +298,28 @@ fn execute_transfer_from(
     })?;
 
     BALANCES.update(deps.storage, &owner_addr, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_sub(amount)
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
     })?;
 
     BALANCES.update(deps.storage, &recipient_addr, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_add(amount_after_fee)
+        Ok(balance.unwrap_or_default().checked_add(amount_after_fee)?)
     })?;
 
     BALANCES.update(deps.storage, &config.fee_receiver, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_add(fee)
+        Ok(balance.unwrap_or_default().checked_add(fee)?)
     })?;
 
+    let tx = Tx {
+        kind: TxKind::TransferFrom,
+        from: owner_addr.clone(),
+        to: recipient_addr.clone(),
+        amount: amount_after_fee,
+        fee,
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &owner_addr, &tx)?;
+    record_tx(deps.storage, &recipient_addr, &tx)?;
+
     Ok(Response::new()
         .add_attribute("action", "transfer_from")
         .add_attribute("from", owner)
@@ -218,14 +329,243 @@ fn execute_transfer_from(
         .add_attribute("fee", fee))
 }
 
-fn execute_burn(
+fn execute_increase_allowance(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<u64>,
+) -> Result<Response, StdError> {
+    let spender_addr = deps.api.addr_validate(&spender)?;
+    if spender_addr == info.sender {
+        return Err(StdError::generic_err("Cannot set allowance for own account"));
+    }
+
+    let allowance = ALLOWANCES.update(
+        deps.storage,
+        (&info.sender, &spender_addr),
+        |allowance| -> StdResult<_> {
+            let mut allowance = allowance.unwrap_or_default();
+            allowance.allowance += amount;
+            allowance.expires = expires.unwrap_or(0);
+            Ok(allowance)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "increase_allowance")
+        .add_attribute("owner", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("amount", allowance.allowance))
+}
+
+fn execute_decrease_allowance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<u64>,
+) -> Result<Response, StdError> {
+    let spender_addr = deps.api.addr_validate(&spender)?;
+
+    let allowance = ALLOWANCES.update(
+        deps.storage,
+        (&info.sender, &spender_addr),
+        |allowance| -> StdResult<_> {
+            let mut allowance = allowance.unwrap_or_default();
+            allowance.allowance = allowance.allowance.saturating_sub(amount);
+            if let Some(expires) = expires {
+                allowance.expires = expires;
+            }
+            Ok(allowance)
+        },
+    )?;
+
+    if allowance.allowance.is_zero() {
+        ALLOWANCES.remove(deps.storage, (&info.sender, &spender_addr));
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "decrease_allowance")
+        .add_attribute("owner", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_send(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    amount: Uint128,
+    msg: Binary,
+) -> Result<Response, StdError> {
+    let config = CONFIG.load(deps.storage)?;
+    let contract_addr = deps.api.addr_validate(&contract)?;
+
+    let fee = amount * config.fee_percent;
+    let amount_after_fee = amount.checked_sub(fee)?;
+
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    BALANCES.update(deps.storage, &contract_addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount_after_fee)?)
+    })?;
+
+    BALANCES.update(deps.storage, &config.fee_receiver, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(fee)?)
+    })?;
+
+    let tx = Tx {
+        kind: TxKind::Transfer,
+        from: info.sender.clone(),
+        to: contract_addr.clone(),
+        amount: amount_after_fee,
+        fee,
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &info.sender, &tx)?;
+    record_tx(deps.storage, &contract_addr, &tx)?;
+
+    let receive_msg = Cw20ReceiveMsg {
+        sender: info.sender.to_string(),
+        amount: amount_after_fee,
+        msg,
+    }
+    .into_cosmos_msg(contract_addr)?;
+
+    Ok(Response::new()
+        .add_message(receive_msg)
+        .add_attribute("action", "send")
+        .add_attribute("from", info.sender)
+        .add_attribute("to", contract)
+        .add_attribute("amount", amount_after_fee)
+        .add_attribute("fee", fee))
+}
+
+fn execute_burn_from(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    amount: Uint128,
+) -> Result<Response, StdError> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+
+    let allowance = ALLOWANCES.load(deps.storage, (&owner_addr, &info.sender))?;
+    assert_not_expired(&allowance, &env)?;
+
+    ALLOWANCES.update(deps.storage, (&owner_addr, &info.sender), |allowance| {
+        let mut allowance = allowance.unwrap_or_default();
+        allowance.allowance = allowance.allowance.checked_sub(amount)?;
+        Ok(allowance)
+    })?;
+
+    BALANCES.update(deps.storage, &owner_addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    TOTAL_BURNED.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+
+    TOKEN_INFO.update(deps.storage, |mut info| -> StdResult<_> {
+        info.total_supply = info.total_supply.checked_sub(amount)?;
+        Ok(info)
+    })?;
+
+    let tx = Tx {
+        kind: TxKind::Burn,
+        from: owner_addr.clone(),
+        to: owner_addr.clone(),
+        amount,
+        fee: Uint128::zero(),
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &owner_addr, &tx)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "burn_from")
+        .add_attribute("from", owner)
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_send_from(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    contract: String,
     amount: Uint128,
+    msg: Binary,
 ) -> Result<Response, StdError> {
     let config = CONFIG.load(deps.storage)?;
-    
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let contract_addr = deps.api.addr_validate(&contract)?;
+
+    let allowance = ALLOWANCES.load(deps.storage, (&owner_addr, &info.sender))?;
+    assert_not_expired(&allowance, &env)?;
+
+    ALLOWANCES.update(deps.storage, (&owner_addr, &info.sender), |allowance| {
+        let mut allowance = allowance.unwrap_or_default();
+        allowance.allowance = allowance.allowance.checked_sub(amount)?;
+        Ok(allowance)
+    })?;
+
+    let fee = amount * config.fee_percent;
+    let amount_after_fee = amount.checked_sub(fee)?;
+
+    BALANCES.update(deps.storage, &owner_addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    BALANCES.update(deps.storage, &contract_addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount_after_fee)?)
+    })?;
+
+    BALANCES.update(deps.storage, &config.fee_receiver, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(fee)?)
+    })?;
+
+    let tx = Tx {
+        kind: TxKind::TransferFrom,
+        from: owner_addr.clone(),
+        to: contract_addr.clone(),
+        amount: amount_after_fee,
+        fee,
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &owner_addr, &tx)?;
+    record_tx(deps.storage, &contract_addr, &tx)?;
+
+    let receive_msg = Cw20ReceiveMsg {
+        sender: info.sender.to_string(),
+        amount: amount_after_fee,
+        msg,
+    }
+    .into_cosmos_msg(contract_addr)?;
+
+    Ok(Response::new()
+        .add_message(receive_msg)
+        .add_attribute("action", "send_from")
+        .add_attribute("from", owner)
+        .add_attribute("to", contract)
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount_after_fee)
+        .add_attribute("fee", fee))
+}
+
+fn execute_burn(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, StdError> {
+    let config = CONFIG.load(deps.storage)?;
+
     if info.sender != config.owner {
         return Err(StdError::generic_err("Unauthorized"));
     }
@@ -235,7 +575,7 @@ fn execute_burn(
     }
 
     BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_sub(amount)
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
     })?;
 
     TOTAL_BURNED.update(deps.storage, |total| -> StdResult<_> {
@@ -247,6 +587,16 @@ fn execute_burn(
         Ok(info)
     })?;
 
+    let tx = Tx {
+        kind: TxKind::Burn,
+        from: info.sender.clone(),
+        to: info.sender.clone(),
+        amount,
+        fee: Uint128::zero(),
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &info.sender, &tx)?;
+
     Ok(Response::new()
         .add_attribute("action", "burn")
         .add_attribute("from", info.sender)
@@ -257,15 +607,27 @@ fn execute_mint(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    recipient: Option<String>,
 ) -> Result<Response, StdError> {
     let config = CONFIG.load(deps.storage)?;
-    
+
     if !config.mint_enabled {
         return Err(StdError::generic_err("Mint is disabled"));
     }
 
-    let mut mint_info = MINT_INFO.may_load(deps.storage, &info.sender)?.unwrap_or(MintInfo {
+    let beneficiary = match recipient {
+        Some(recipient) => {
+            if Some(&info.sender) != config.factory.as_ref() {
+                return Err(StdError::generic_err("Unauthorized"));
+            }
+            deps.api.addr_validate(&recipient)?
+        }
+        None => info.sender,
+    };
+
+    let mut mint_info = MINT_INFO.may_load(deps.storage, &beneficiary)?.unwrap_or(MintInfo {
         last_mint_time: Timestamp::from_seconds(0),
+        total_minted: Uint128::zero(),
     });
 
     let current_time = env.block.time;
@@ -275,11 +637,25 @@ fn execute_mint(
         return Err(StdError::generic_err("You have already minted recently. Please wait."));
     }
 
+    if let Some(cap) = config.per_address_mint_cap {
+        if mint_info.total_minted + config.mint_amount > cap {
+            return Err(StdError::generic_err("Address mint cap exceeded"));
+        }
+    }
+
+    if let Some(max_supply) = config.max_supply {
+        let token_info = TOKEN_INFO.load(deps.storage)?;
+        if token_info.total_supply + config.mint_amount > max_supply {
+            return Err(StdError::generic_err("Minting would exceed max supply"));
+        }
+    }
+
     mint_info.last_mint_time = current_time;
-    MINT_INFO.save(deps.storage, &info.sender, &mint_info)?;
+    mint_info.total_minted += config.mint_amount;
+    MINT_INFO.save(deps.storage, &beneficiary, &mint_info)?;
 
-    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
-        balance.unwrap_or_default().checked_add(config.mint_amount)
+    BALANCES.update(deps.storage, &beneficiary, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(config.mint_amount)?)
     })?;
 
     TOKEN_INFO.update(deps.storage, |mut info| -> StdResult<_> {
@@ -287,12 +663,230 @@ fn execute_mint(
         Ok(info)
     })?;
 
+    let tx = Tx {
+        kind: TxKind::Mint,
+        from: beneficiary.clone(),
+        to: beneficiary.clone(),
+        amount: config.mint_amount,
+        fee: Uint128::zero(),
+        block_time: current_time,
+    };
+    record_tx(deps.storage, &beneficiary, &tx)?;
+
     Ok(Response::new()
         .add_attribute("action", "mint")
-        .add_attribute("to", info.sender)
+        .add_attribute("to", beneficiary)
         .add_attribute("amount", config.mint_amount))
 }
 
+fn execute_set_mint_cap(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, StdError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(StdError::generic_err("Unauthorized"));
+    }
+    if config.immutable_mode {
+        return Err(StdError::generic_err("Contract is locked"));
+    }
+
+    config.per_address_mint_cap = amount;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_mint_cap")
+        .add_attribute("per_address_mint_cap", format!("{amount:?}")))
+}
+
+/// Authorizes either the real owner directly, or `config.factory` relaying on the owner's
+/// behalf (the factory only forwards these calls once it has verified the real caller is the
+/// token's creator — see `lib.rs`'s `set_mint_enabled`/`lock_ownership`).
+fn assert_owner_or_factory(info: &MessageInfo, config: &Config) -> Result<(), StdError> {
+    if info.sender == config.owner || config.factory.as_ref() == Some(&info.sender) {
+        Ok(())
+    } else {
+        Err(StdError::generic_err("Unauthorized"))
+    }
+}
+
+fn execute_set_mint_amount(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, StdError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_owner_or_factory(&info, &config)?;
+    if config.immutable_mode {
+        return Err(StdError::generic_err("Contract is locked"));
+    }
+
+    config.mint_amount = amount;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_mint_amount")
+        .add_attribute("mint_amount", amount))
+}
+
+fn execute_set_mint_enabled(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, StdError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_owner_or_factory(&info, &config)?;
+    if config.immutable_mode {
+        return Err(StdError::generic_err("Contract is locked"));
+    }
+
+    config.mint_enabled = enabled;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_mint_enabled")
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+fn execute_lock_ownership(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, StdError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_owner_or_factory(&info, &config)?;
+    if config.immutable_mode {
+        return Err(StdError::generic_err("Contract is locked"));
+    }
+
+    config.immutable_mode = true;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "lock_ownership"))
+}
+
+fn execute_transfer_ownership(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, StdError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_owner_or_factory(&info, &config)?;
+    if config.immutable_mode {
+        return Err(StdError::generic_err("Contract is locked"));
+    }
+
+    let new_owner_addr = deps.api.addr_validate(&new_owner)?;
+    config.owner = new_owner_addr;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer_ownership")
+        .add_attribute("new_owner", new_owner))
+}
+
+fn execute_add_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    cap: Option<Uint128>,
+) -> Result<Response, StdError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(StdError::generic_err("Unauthorized"));
+    }
+
+    let minter_addr = deps.api.addr_validate(&address)?;
+    let minted = MINTERS
+        .may_load(deps.storage, &minter_addr)?
+        .map(|existing| existing.minted)
+        .unwrap_or_default();
+    MINTERS.save(deps.storage, &minter_addr, &MinterData { cap, minted })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_minter")
+        .add_attribute("minter", address))
+}
+
+fn execute_remove_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, StdError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(StdError::generic_err("Unauthorized"));
+    }
+
+    let minter_addr = deps.api.addr_validate(&address)?;
+    MINTERS.remove(deps.storage, &minter_addr);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_minter")
+        .add_attribute("minter", address))
+}
+
+fn execute_mint_to(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, StdError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut minter = MINTERS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or_else(|| StdError::generic_err("Unauthorized"))?;
+
+    if let Some(cap) = minter.cap {
+        if minter.minted + amount > cap {
+            return Err(StdError::generic_err("Minter cap exceeded"));
+        }
+    }
+
+    let token_info = TOKEN_INFO.load(deps.storage)?;
+    if let Some(max_supply) = config.max_supply {
+        if token_info.total_supply + amount > max_supply {
+            return Err(StdError::generic_err("Minting would exceed max supply"));
+        }
+    }
+
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+
+    minter.minted += amount;
+    MINTERS.save(deps.storage, &info.sender, &minter)?;
+
+    BALANCES.update(deps.storage, &recipient_addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    TOKEN_INFO.update(deps.storage, |mut info| -> StdResult<_> {
+        info.total_supply = info.total_supply.checked_add(amount)?;
+        Ok(info)
+    })?;
+
+    let tx = Tx {
+        kind: TxKind::Mint,
+        from: info.sender.clone(),
+        to: recipient_addr.clone(),
+        amount,
+        fee: Uint128::zero(),
+        block_time: env.block.time,
+    };
+    record_tx(deps.storage, &recipient_addr, &tx)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "mint_to")
+        .add_attribute("minter", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount))
+}
+
 #[entry_point]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -312,31 +906,313 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 total_supply: info.total_supply,
             })
         }
+        QueryMsg::Allowance { owner, spender } => {
+            let owner_addr = deps.api.addr_validate(&owner)?;
+            let spender_addr = deps.api.addr_validate(&spender)?;
+            let allowance = ALLOWANCES
+                .may_load(deps.storage, (&owner_addr, &spender_addr))?
+                .unwrap_or_default();
+            to_json_binary(&allowance)
+        }
         QueryMsg::GetConfig {} => to_json_binary(&CONFIG.load(deps.storage)?),
         QueryMsg::GetMintInfo { address } => {
             let addr = deps.api.addr_validate(&address)?;
+            let config = CONFIG.load(deps.storage)?;
             let mint_info = MINT_INFO.may_load(deps.storage, &addr)?.unwrap_or(MintInfo {
                 last_mint_time: Timestamp::from_seconds(0),
+                total_minted: Uint128::zero(),
             });
-            to_json_binary(&mint_info)
+            let remaining_mint_allowance = config
+                .per_address_mint_cap
+                .map(|cap| cap.saturating_sub(mint_info.total_minted));
+
+            to_json_binary(&MintInfoResponse {
+                last_mint_time: mint_info.last_mint_time,
+                total_minted: mint_info.total_minted,
+                remaining_mint_allowance,
+            })
         }
         QueryMsg::GetTotalBurned {} => {
             let total_burned = TOTAL_BURNED.load(deps.storage)?;
             to_json_binary(&TotalBurnedResponse { total_burned })
         }
+        QueryMsg::TransactionHistory {
+            address,
+            start_after,
+            limit,
+        } => {
+            let addr = deps.api.addr_validate(&address)?;
+            let limit = limit.unwrap_or(DEFAULT_HISTORY_LIMIT).min(MAX_HISTORY_LIMIT) as u64;
+            let count = TX_COUNT.may_load(deps.storage, &addr)?.unwrap_or_default();
+            let start = start_after.unwrap_or(count).min(count);
+
+            let txs = (0..start)
+                .rev()
+                .take(limit as usize)
+                .map(|seq| TRANSACTIONS.load(deps.storage, (&addr, seq)))
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_json_binary(&TransactionHistoryResponse { txs })
+        }
         _ => Err(StdError::generic_err("Unsupported query message")),
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
 pub struct AllowanceResponse {
     pub allowance: Uint128,
+    /// Unix timestamp (seconds) after which the allowance can no longer be spent. `0` means the
+    /// allowance never expires.
     pub expires: u64,
 }
 
+fn assert_not_expired(allowance: &AllowanceResponse, env: &Env) -> Result<(), StdError> {
+    if allowance.expires != 0 && env.block.time.seconds() >= allowance.expires {
+        return Err(StdError::generic_err("Allowance expired"));
+    }
+    Ok(())
+}
+
 pub const TOKEN_INFO: Item<TokenInfo> = Item::new("token_info");
 pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
 pub const ALLOWANCES: Map<(&Addr, &Addr), AllowanceResponse> = Map::new("allowances");
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const MINT_INFO: Map<&Addr, MintInfo> = Map::new("mint_info");
-pub const TOTAL_BURNED: Item<Uint128> = Item::new("total_burned");
\ No newline at end of file
+pub const TOTAL_BURNED: Item<Uint128> = Item::new("total_burned");
+pub const TRANSACTIONS: Map<(&Addr, u64), Tx> = Map::new("transactions");
+pub const TX_COUNT: Map<&Addr, u64> = Map::new("tx_count");
+pub const MINTERS: Map<&Addr, MinterData> = Map::new("minters");
+
+fn record_tx(storage: &mut dyn Storage, account: &Addr, tx: &Tx) -> StdResult<()> {
+    let seq = TX_COUNT.may_load(storage, account)?.unwrap_or_default();
+    TRANSACTIONS.save(storage, (account, seq), tx)?;
+    TX_COUNT.save(storage, account, &(seq + 1))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    fn setup() -> cosmwasm_std::OwnedDeps<
+        cosmwasm_std::testing::MockStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    > {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                name: "Test".to_string(),
+                symbol: "TST".to_string(),
+                decimals: 6,
+                initial_supply: Uint128::new(1_000),
+                fee_receiver: "fee_receiver".to_string(),
+                owner: None,
+                factory: None,
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    #[test]
+    fn transaction_history_is_paginated_in_reverse_chronological_order() {
+        let mut deps = setup();
+        execute_transfer(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            "bob".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap();
+        execute_transfer(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            "bob".to_string(),
+            Uint128::new(50),
+        )
+        .unwrap();
+
+        let creator = Addr::unchecked("creator");
+        assert_eq!(TX_COUNT.load(deps.as_ref().storage, &creator).unwrap(), 2);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::TransactionHistory {
+                address: "creator".to_string(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+        let history: TransactionHistoryResponse = cosmwasm_std::from_json(res).unwrap();
+        assert_eq!(history.txs.len(), 1);
+        // Most recent transfer (amount 50) comes first.
+        assert_eq!(history.txs[0].amount, Uint128::new(50));
+    }
+
+    #[test]
+    fn allowance_decreases_as_it_is_spent_and_is_removed_at_zero() {
+        let mut deps = setup();
+        execute_increase_allowance(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            "spender".to_string(),
+            Uint128::new(100),
+            None,
+        )
+        .unwrap();
+
+        execute_transfer_from(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("spender", &[]),
+            "creator".to_string(),
+            "bob".to_string(),
+            Uint128::new(40),
+        )
+        .unwrap();
+
+        let owner = Addr::unchecked("creator");
+        let spender = Addr::unchecked("spender");
+        let allowance = ALLOWANCES
+            .load(deps.as_ref().storage, (&owner, &spender))
+            .unwrap();
+        assert_eq!(allowance.allowance, Uint128::new(60));
+
+        execute_decrease_allowance(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            "spender".to_string(),
+            Uint128::new(60),
+            None,
+        )
+        .unwrap();
+
+        assert!(ALLOWANCES
+            .may_load(deps.as_ref().storage, (&owner, &spender))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn expired_allowance_cannot_be_spent() {
+        let mut deps = setup();
+        execute_increase_allowance(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            "spender".to_string(),
+            Uint128::new(100),
+            Some(1),
+        )
+        .unwrap();
+
+        let err = execute_transfer_from(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("spender", &[]),
+            "creator".to_string(),
+            "bob".to_string(),
+            Uint128::new(40),
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    fn mint_to_enforces_minter_cap() {
+        let mut deps = setup();
+        execute_add_minter(
+            deps.as_mut(),
+            mock_info("creator", &[]),
+            "minter1".to_string(),
+            Some(Uint128::new(100)),
+        )
+        .unwrap();
+
+        execute_mint_to(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minter1", &[]),
+            "bob".to_string(),
+            Uint128::new(60),
+        )
+        .unwrap();
+
+        let err = execute_mint_to(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minter1", &[]),
+            "bob".to_string(),
+            Uint128::new(50),
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    fn re_adding_a_minter_preserves_its_minted_total() {
+        let mut deps = setup();
+        execute_add_minter(
+            deps.as_mut(),
+            mock_info("creator", &[]),
+            "minter1".to_string(),
+            Some(Uint128::new(100)),
+        )
+        .unwrap();
+        execute_mint_to(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minter1", &[]),
+            "bob".to_string(),
+            Uint128::new(60),
+        )
+        .unwrap();
+
+        // Re-adding with a larger cap must not reset what's already been minted.
+        execute_add_minter(
+            deps.as_mut(),
+            mock_info("creator", &[]),
+            "minter1".to_string(),
+            Some(Uint128::new(200)),
+        )
+        .unwrap();
+
+        let minter_addr = Addr::unchecked("minter1");
+        let minter = MINTERS.load(deps.as_ref().storage, &minter_addr).unwrap();
+        assert_eq!(minter.minted, Uint128::new(60));
+        assert_eq!(minter.cap, Some(Uint128::new(200)));
+    }
+
+    #[test]
+    fn mint_respects_interval_and_per_address_cap() {
+        let mut deps = setup();
+        execute_set_mint_enabled(deps.as_mut(), mock_env(), mock_info("creator", &[]), true).unwrap();
+
+        execute_mint(deps.as_mut(), mock_env(), mock_info("alice", &[]), None).unwrap();
+
+        // A second mint in the same block is still within the interval.
+        let err = execute_mint(deps.as_mut(), mock_env(), mock_info("alice", &[]), None).unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        // A cap smaller than a single mint_amount rejects a first-time minter outright.
+        execute_set_mint_cap(
+            deps.as_mut(),
+            mock_info("creator", &[]),
+            Some(Uint128::new(1)),
+        )
+        .unwrap();
+        let err = execute_mint(deps.as_mut(), mock_env(), mock_info("bob", &[]), None).unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+}
\ No newline at end of file
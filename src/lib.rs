@@ -1,14 +1,18 @@
 use cosmwasm_std::{
-    entry_point, to_json_binary, Addr, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
-    BankMsg, Coin,
+    entry_point, to_json_binary, Addr, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw_storage_plus::{Item, Map};
+use cw_utils::parse_reply_instantiate_data;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+const INSTANTIATE_TOKEN_REPLY_ID: u64 = 1;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct InstantiateMsg {
     pub owner: String,
+    pub token_code_id: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -21,11 +25,6 @@ pub enum ExecuteMsg {
     Mint {
         token_address: String,
     },
-    Transfer {
-        token_address: String,
-        recipient: String,
-        amount: Uint128,
-    },
     SetMintEnabled {
         token_address: String,
         enabled: bool,
@@ -35,15 +34,46 @@ pub enum ExecuteMsg {
     },
 }
 
+/// Mirrors the custom-token contract's `InstantiateMsg` shape so the factory can instantiate it
+/// without depending on that contract's crate.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct TokenInstantiateMsg {
+    name: String,
+    symbol: String,
+    decimals: u8,
+    initial_supply: Uint128,
+    fee_receiver: String,
+    owner: Option<String>,
+    factory: Option<String>,
+}
+
+/// Mirrors the subset of the custom-token contract's `ExecuteMsg` the factory drives on its
+/// children's behalf.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum TokenExecuteMsg {
+    /// Only honored by the child when sent by the `factory` address it was instantiated with;
+    /// credits `recipient` instead of crediting this contract's own balance.
+    Mint { recipient: Option<String> },
+    /// Honored by the child both from its real owner and from its configured `factory` — see
+    /// `Config::factory` on the custom-token contract.
+    SetMintEnabled { enabled: bool },
+    LockOwnership {},
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct TokenInfo {
     pub name: String,
     pub symbol: String,
-    pub supply: Uint128,
-    pub fee_receiver: Addr,
     pub creator: Addr,
-    pub mint_enabled: bool,
-    pub immutable_mode: bool,
+}
+
+/// The `CreateToken` request waiting on its child-contract instantiation reply.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PendingToken {
+    creator: Addr,
+    name: String,
+    symbol: String,
 }
 
 #[derive(Error, Debug)]
@@ -59,9 +89,11 @@ pub enum ContractError {
 }
 
 pub const OWNER: Item<Addr> = Item::new("owner");
+pub const TOKEN_CODE_ID: Item<u64> = Item::new("token_code_id");
 pub const TOKENS: Map<&Addr, Vec<Addr>> = Map::new("tokens");
 pub const ALL_TOKENS: Item<Vec<Addr>> = Item::new("all_tokens");
 pub const TOKEN_INFO: Map<&Addr, TokenInfo> = Map::new("token_info");
+const PENDING_TOKEN: Item<PendingToken> = Item::new("pending_token");
 
 #[entry_point]
 pub fn instantiate(
@@ -72,13 +104,15 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     let owner = deps.api.addr_validate(&msg.owner)?;
     OWNER.save(deps.storage, &owner)?;
+    TOKEN_CODE_ID.save(deps.storage, &msg.token_code_id)?;
+    ALL_TOKENS.save(deps.storage, &vec![])?;
     Ok(Response::new())
 }
 
 #[entry_point]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -87,140 +121,179 @@ pub fn execute(
             name,
             symbol,
             initial_supply,
-        } => create_token(deps, info, name, symbol, initial_supply),
+        } => create_token(deps, env, info, name, symbol, initial_supply),
         ExecuteMsg::Mint { token_address } => mint(deps, info, token_address),
-        ExecuteMsg::Transfer {
-            token_address,
-            recipient,
-            amount,
-        } => transfer(deps, info, token_address, recipient, amount),
-        ExecuteMsg::SetMintEnabled {
-            token_address,
-            enabled,
-        } => set_mint_enabled(deps, info, token_address, enabled),
+        ExecuteMsg::SetMintEnabled { token_address, enabled } => {
+            set_mint_enabled(deps, info, token_address, enabled)
+        }
         ExecuteMsg::LockOwnership { token_address } => lock_ownership(deps, info, token_address),
     }
 }
 
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        INSTANTIATE_TOKEN_REPLY_ID => handle_instantiate_reply(deps, msg),
+        id => Err(ContractError::Std(StdError::generic_err(format!(
+            "Unknown reply id: {id}"
+        )))),
+    }
+}
+
 fn create_token(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     name: String,
     symbol: String,
     initial_supply: Uint128,
 ) -> Result<Response, ContractError> {
-    let owner = OWNER.load(deps.storage)?;
-    let token_address = deps.api.addr_validate(&info.sender.to_string())?;
+    let token_code_id = TOKEN_CODE_ID.load(deps.storage)?;
+
+    PENDING_TOKEN.save(
+        deps.storage,
+        &PendingToken {
+            creator: info.sender.clone(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+        },
+    )?;
+
+    let instantiate_msg = WasmMsg::Instantiate {
+        admin: None,
+        code_id: token_code_id,
+        msg: to_json_binary(&TokenInstantiateMsg {
+            name,
+            symbol: symbol.clone(),
+            decimals: 6,
+            initial_supply,
+            fee_receiver: info.sender.to_string(),
+            owner: Some(info.sender.to_string()),
+            factory: Some(env.contract.address.to_string()),
+        })?,
+        funds: vec![],
+        label: format!("token-{symbol}"),
+    };
+
+    let submsg = SubMsg::reply_on_success(instantiate_msg, INSTANTIATE_TOKEN_REPLY_ID);
+
+    Ok(Response::new()
+        .add_submessage(submsg)
+        .add_attribute("action", "create_token"))
+}
+
+fn handle_instantiate_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let pending = PENDING_TOKEN.load(deps.storage)?;
+    PENDING_TOKEN.remove(deps.storage);
+
+    let res = parse_reply_instantiate_data(msg).map_err(|err| StdError::generic_err(err.to_string()))?;
+    let token_address = deps.api.addr_validate(&res.contract_address)?;
 
     let token_info = TokenInfo {
-        name,
-        symbol,
-        supply: initial_supply,
-        fee_receiver: owner.clone(),
-        creator: info.sender.clone(),
-        mint_enabled: false,
-        immutable_mode: false,
+        name: pending.name,
+        symbol: pending.symbol,
+        creator: pending.creator.clone(),
     };
 
     TOKEN_INFO.save(deps.storage, &token_address, &token_info)?;
+
     ALL_TOKENS.update(deps.storage, |mut all_tokens| -> StdResult<_> {
         all_tokens.push(token_address.clone());
         Ok(all_tokens)
     })?;
 
-    // Corrigindo erro de Option<Vec> no TOKENS
-    TOKENS.update(deps.storage, &info.sender, |tokens| -> StdResult<_> {
+    TOKENS.update(deps.storage, &pending.creator, |tokens| -> StdResult<_> {
         let mut tokens = tokens.unwrap_or_default();
         tokens.push(token_address.clone());
         Ok(tokens)
     })?;
 
-    Ok(Response::new().add_attribute("action", "create_token"))
+    Ok(Response::new()
+        .add_attribute("action", "instantiate_token_reply")
+        .add_attribute("token_address", token_address))
 }
 
+/// Relays a mint to the child token contract on the creator's behalf, crediting the creator
+/// (not this factory) because the child trusts this contract's address to name the real
+/// recipient explicitly — see `TokenExecuteMsg::Mint`.
 fn mint(
     deps: DepsMut,
-    _info: MessageInfo,
+    info: MessageInfo,
     token_address: String,
 ) -> Result<Response, ContractError> {
     let token_address = deps.api.addr_validate(&token_address)?;
-    let mut token_info = TOKEN_INFO.load(deps.storage, &token_address)?;
+    let token_info = TOKEN_INFO.load(deps.storage, &token_address)?;
 
-    if !token_info.mint_enabled {
-        return Err(ContractError::MintDisabled {});
+    if info.sender != token_info.creator {
+        return Err(ContractError::Unauthorized {});
     }
 
-    let mint_amount = Uint128::new(40_000_000_000_000_000); // 0.40 * 10^18
-
-    token_info.supply += mint_amount;
-    TOKEN_INFO.save(deps.storage, &token_address, &token_info)?;
-
-    Ok(Response::new().add_attribute("action", "mint"))
-}
-
-fn transfer(
-    deps: DepsMut,
-    _info: MessageInfo,
-    token_address: String,
-    recipient: String,
-    amount: Uint128,
-) -> Result<Response, ContractError> {
-    let token_address = deps.api.addr_validate(&token_address)?;
-    let recipient = deps.api.addr_validate(&recipient)?;
-
-    let fee = amount.u128() / 100; // 1% de taxa
-    let amount_after_fee = amount - Uint128::new(fee);
-
-    let fee_msg = BankMsg::Send {
-        to_address: token_address.to_string(),
-        amount: vec![Coin {
-            denom: "utoken".to_string(),
-            amount: Uint128::new(fee),
-        }],
-    };
-
-    let transfer_msg = BankMsg::Send {
-        to_address: recipient.to_string(),
-        amount: vec![Coin {
-            denom: "utoken".to_string(),
-            amount: amount_after_fee,
-        }],
+    let msg = WasmMsg::Execute {
+        contract_addr: token_address.to_string(),
+        msg: to_json_binary(&TokenExecuteMsg::Mint {
+            recipient: Some(info.sender.to_string()),
+        })?,
+        funds: vec![],
     };
 
     Ok(Response::new()
-        .add_messages(vec![fee_msg, transfer_msg])
-        .add_attribute("action", "transfer"))
+        .add_message(msg)
+        .add_attribute("action", "mint")
+        .add_attribute("token_address", token_address))
 }
 
+/// Relays a mint-enable toggle to the child on the creator's behalf; the child accepts this
+/// factory as a delegate of its owner once it trusts this contract's address (see
+/// `Config::factory`), so it doesn't matter that `info.sender` on the child's side is this
+/// factory rather than the creator checked here.
 fn set_mint_enabled(
     deps: DepsMut,
-    _info: MessageInfo,
+    info: MessageInfo,
     token_address: String,
     enabled: bool,
 ) -> Result<Response, ContractError> {
     let token_address = deps.api.addr_validate(&token_address)?;
-    let mut token_info = TOKEN_INFO.load(deps.storage, &token_address)?;
+    let token_info = TOKEN_INFO.load(deps.storage, &token_address)?;
 
-    if token_info.immutable_mode {
-        return Err(ContractError::ContractLocked {});
+    if info.sender != token_info.creator {
+        return Err(ContractError::Unauthorized {});
     }
 
-    token_info.mint_enabled = enabled;
-    TOKEN_INFO.save(deps.storage, &token_address, &token_info)?;
+    let msg = WasmMsg::Execute {
+        contract_addr: token_address.to_string(),
+        msg: to_json_binary(&TokenExecuteMsg::SetMintEnabled { enabled })?,
+        funds: vec![],
+    };
 
-    Ok(Response::new().add_attribute("action", "set_mint_enabled"))
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "set_mint_enabled")
+        .add_attribute("token_address", token_address)
+        .add_attribute("enabled", enabled.to_string()))
 }
 
+/// Relays an ownership lock to the child on the creator's behalf — see `set_mint_enabled` for
+/// why trusting this factory as a delegate on the child's side is sound.
 fn lock_ownership(
     deps: DepsMut,
-    _info: MessageInfo,
+    info: MessageInfo,
     token_address: String,
 ) -> Result<Response, ContractError> {
     let token_address = deps.api.addr_validate(&token_address)?;
-    let mut token_info = TOKEN_INFO.load(deps.storage, &token_address)?;
+    let token_info = TOKEN_INFO.load(deps.storage, &token_address)?;
 
-    token_info.immutable_mode = true;
-    TOKEN_INFO.save(deps.storage, &token_address, &token_info)?;
+    if info.sender != token_info.creator {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    Ok(Response::new().add_attribute("action", "lock_ownership"))
+    let msg = WasmMsg::Execute {
+        contract_addr: token_address.to_string(),
+        msg: to_json_binary(&TokenExecuteMsg::LockOwnership {})?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "lock_ownership")
+        .add_attribute("token_address", token_address))
 }
@@ -0,0 +1,484 @@
+use cosmwasm_std::{
+    entry_point, to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Uint128, Uint256, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const CONTRACT_NAME: &str = "crates.io:amm-pool";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct InstantiateMsg {
+    pub token1: String,
+    pub token2: String,
+    pub fee_bps: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    AddLiquidity { amount1: Uint128, amount2: Uint128 },
+    RemoveLiquidity { shares: Uint128 },
+    Swap { offer_token: String, amount_in: Uint128 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Pool {},
+    Share { address: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PoolInfo {
+    pub token1: Addr,
+    pub token2: Addr,
+    pub reserve1: Uint128,
+    pub reserve2: Uint128,
+    pub fee_bps: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PoolResponse {
+    pub reserve1: Uint128,
+    pub reserve2: Uint128,
+    pub total_shares: Uint128,
+    pub price: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ShareResponse {
+    pub shares: Uint128,
+}
+
+pub const POOL_INFO: Item<PoolInfo> = Item::new("pool_info");
+pub const TOTAL_SHARES: Item<Uint128> = Item::new("total_shares");
+pub const SHARES: Map<&Addr, Uint128> = Map::new("shares");
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, StdError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let token1 = deps.api.addr_validate(&msg.token1)?;
+    let token2 = deps.api.addr_validate(&msg.token2)?;
+    if token1 == token2 {
+        return Err(StdError::generic_err("token1 and token2 must differ"));
+    }
+    if msg.fee_bps >= 10_000 {
+        return Err(StdError::generic_err("fee_bps must be less than 10000"));
+    }
+
+    POOL_INFO.save(
+        deps.storage,
+        &PoolInfo {
+            token1,
+            token2,
+            reserve1: Uint128::zero(),
+            reserve2: Uint128::zero(),
+            fee_bps: msg.fee_bps,
+        },
+    )?;
+    TOTAL_SHARES.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new().add_attribute("method", "instantiate"))
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, StdError> {
+    match msg {
+        ExecuteMsg::AddLiquidity { amount1, amount2 } => {
+            execute_add_liquidity(deps, env, info, amount1, amount2)
+        }
+        ExecuteMsg::RemoveLiquidity { shares } => execute_remove_liquidity(deps, info, shares),
+        ExecuteMsg::Swap { offer_token, amount_in } => {
+            execute_swap(deps, env, info, offer_token, amount_in)
+        }
+    }
+}
+
+fn execute_add_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount1: Uint128,
+    amount2: Uint128,
+) -> Result<Response, StdError> {
+    if amount1.is_zero() || amount2.is_zero() {
+        return Err(StdError::generic_err("Amounts must be greater than 0"));
+    }
+
+    let mut pool = POOL_INFO.load(deps.storage)?;
+    let mut total_shares = TOTAL_SHARES.load(deps.storage)?;
+
+    let minted_shares = if total_shares.is_zero() {
+        isqrt(amount1.full_mul(amount2))
+    } else {
+        // Reject deposits that don't match the pool's current ratio instead of silently
+        // donating the imbalance to the pool without crediting it as shares.
+        if amount1.full_mul(pool.reserve2) != amount2.full_mul(pool.reserve1) {
+            return Err(StdError::generic_err(
+                "Deposit amounts must match the pool's current reserve ratio",
+            ));
+        }
+        amount1.multiply_ratio(total_shares, pool.reserve1)
+    };
+
+    if minted_shares.is_zero() {
+        return Err(StdError::generic_err("Insufficient liquidity minted"));
+    }
+
+    pool.reserve1 += amount1;
+    pool.reserve2 += amount2;
+    POOL_INFO.save(deps.storage, &pool)?;
+
+    total_shares += minted_shares;
+    TOTAL_SHARES.save(deps.storage, &total_shares)?;
+
+    SHARES.update(deps.storage, &info.sender, |shares| -> StdResult<_> {
+        Ok(shares.unwrap_or_default() + minted_shares)
+    })?;
+
+    let pull_token1 = transfer_from_msg(&pool.token1, &info.sender, &env.contract.address, amount1)?;
+    let pull_token2 = transfer_from_msg(&pool.token2, &info.sender, &env.contract.address, amount2)?;
+
+    Ok(Response::new()
+        .add_messages(vec![pull_token1, pull_token2])
+        .add_attribute("action", "add_liquidity")
+        .add_attribute("shares_minted", minted_shares))
+}
+
+fn execute_remove_liquidity(
+    deps: DepsMut,
+    info: MessageInfo,
+    shares: Uint128,
+) -> Result<Response, StdError> {
+    if shares.is_zero() {
+        return Err(StdError::generic_err("Shares must be greater than 0"));
+    }
+
+    let mut pool = POOL_INFO.load(deps.storage)?;
+    let mut total_shares = TOTAL_SHARES.load(deps.storage)?;
+    let user_shares = SHARES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+
+    if shares > user_shares {
+        return Err(StdError::generic_err("Insufficient shares"));
+    }
+
+    let amount1 = pool.reserve1.multiply_ratio(shares, total_shares);
+    let amount2 = pool.reserve2.multiply_ratio(shares, total_shares);
+
+    if amount1.is_zero() || amount2.is_zero() {
+        return Err(StdError::generic_err("Withdrawal amount too small"));
+    }
+
+    pool.reserve1 = pool.reserve1.checked_sub(amount1)?;
+    pool.reserve2 = pool.reserve2.checked_sub(amount2)?;
+    POOL_INFO.save(deps.storage, &pool)?;
+
+    total_shares = total_shares.checked_sub(shares)?;
+    TOTAL_SHARES.save(deps.storage, &total_shares)?;
+
+    let remaining_shares = user_shares.checked_sub(shares)?;
+    if remaining_shares.is_zero() {
+        SHARES.remove(deps.storage, &info.sender);
+    } else {
+        SHARES.save(deps.storage, &info.sender, &remaining_shares)?;
+    }
+
+    let send_token1 = transfer_msg(&pool.token1, &info.sender, amount1)?;
+    let send_token2 = transfer_msg(&pool.token2, &info.sender, amount2)?;
+
+    Ok(Response::new()
+        .add_messages(vec![send_token1, send_token2])
+        .add_attribute("action", "remove_liquidity")
+        .add_attribute("shares_burned", shares)
+        .add_attribute("amount1", amount1)
+        .add_attribute("amount2", amount2))
+}
+
+fn execute_swap(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    offer_token: String,
+    amount_in: Uint128,
+) -> Result<Response, StdError> {
+    if amount_in.is_zero() {
+        return Err(StdError::generic_err("amount_in must be greater than 0"));
+    }
+
+    let mut pool = POOL_INFO.load(deps.storage)?;
+    let offer_addr = deps.api.addr_validate(&offer_token)?;
+
+    let (reserve_in, reserve_out, offer_is_token1) = if offer_addr == pool.token1 {
+        (pool.reserve1, pool.reserve2, true)
+    } else if offer_addr == pool.token2 {
+        (pool.reserve2, pool.reserve1, false)
+    } else {
+        return Err(StdError::generic_err("offer_token is not part of this pool"));
+    };
+
+    let amount_in_after_fee = amount_in.multiply_ratio(10_000 - pool.fee_bps, 10_000u128);
+
+    let k = reserve_in.full_mul(reserve_out);
+    let new_reserve_out = k / Uint256::from(reserve_in + amount_in_after_fee);
+    let amount_out: Uint128 = Uint256::from(reserve_out)
+        .checked_sub(new_reserve_out)
+        .map_err(|_| StdError::generic_err("Insufficient output amount"))?
+        .try_into()
+        .map_err(|_| StdError::generic_err("Output amount overflow"))?;
+
+    if amount_out.is_zero() || amount_out >= reserve_out {
+        return Err(StdError::generic_err("Insufficient output amount"));
+    }
+
+    let ask_token = if offer_is_token1 {
+        pool.reserve1 += amount_in;
+        pool.reserve2 -= amount_out;
+        pool.token2.clone()
+    } else {
+        pool.reserve2 += amount_in;
+        pool.reserve1 -= amount_out;
+        pool.token1.clone()
+    };
+    POOL_INFO.save(deps.storage, &pool)?;
+
+    let pull_offer = transfer_from_msg(&offer_addr, &info.sender, &env.contract.address, amount_in)?;
+    let send_ask = transfer_msg(&ask_token, &info.sender, amount_out)?;
+
+    Ok(Response::new()
+        .add_messages(vec![pull_offer, send_ask])
+        .add_attribute("action", "swap")
+        .add_attribute("offer_amount", amount_in)
+        .add_attribute("return_amount", amount_out))
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Pool {} => {
+            let pool = POOL_INFO.load(deps.storage)?;
+            let total_shares = TOTAL_SHARES.load(deps.storage)?;
+            let price = if pool.reserve1.is_zero() {
+                Decimal::zero()
+            } else {
+                Decimal::from_ratio(pool.reserve2, pool.reserve1)
+            };
+
+            to_json_binary(&PoolResponse {
+                reserve1: pool.reserve1,
+                reserve2: pool.reserve2,
+                total_shares,
+                price,
+            })
+        }
+        QueryMsg::Share { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+            let shares = SHARES.may_load(deps.storage, &addr)?.unwrap_or_default();
+            to_json_binary(&ShareResponse { shares })
+        }
+    }
+}
+
+fn transfer_from_msg(
+    token: &Addr,
+    owner: &Addr,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+            owner: owner.to_string(),
+            recipient: recipient.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    })
+}
+
+fn transfer_msg(token: &Addr, recipient: &Addr, amount: Uint128) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: recipient.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    })
+}
+
+/// Integer square root via Newton's method, rounding down so LP share minting never overmints.
+fn isqrt(value: Uint256) -> Uint128 {
+    if value.is_zero() {
+        return Uint128::zero();
+    }
+
+    let mut x = value;
+    let mut y = (x + Uint256::one()) / Uint256::from(2u128);
+    while y < x {
+        x = y;
+        y = (x + value / x) / Uint256::from(2u128);
+    }
+
+    x.try_into().unwrap_or(Uint128::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    fn setup(fee_bps: u64) -> cosmwasm_std::OwnedDeps<
+        cosmwasm_std::testing::MockStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    > {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                token1: "token1".to_string(),
+                token2: "token2".to_string(),
+                fee_bps,
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    #[test]
+    fn first_add_liquidity_mints_isqrt_of_product() {
+        let mut deps = setup(0);
+        execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            Uint128::new(100),
+            Uint128::new(400),
+        )
+        .unwrap();
+
+        assert_eq!(TOTAL_SHARES.load(deps.as_ref().storage).unwrap(), Uint128::new(200));
+        assert_eq!(
+            SHARES
+                .load(deps.as_ref().storage, &Addr::unchecked("alice"))
+                .unwrap(),
+            Uint128::new(200)
+        );
+    }
+
+    #[test]
+    fn add_liquidity_rejects_mismatched_ratio() {
+        let mut deps = setup(0);
+        execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            Uint128::new(100),
+            Uint128::new(100),
+        )
+        .unwrap();
+
+        let err = execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            Uint128::new(50),
+            Uint128::new(60),
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    fn add_liquidity_accepts_matching_ratio() {
+        let mut deps = setup(0);
+        // 100/100 makes the first deposit's isqrt(amount1 * amount2) an exact round number, so
+        // the expected share totals below aren't obscured by rounding.
+        execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            Uint128::new(100),
+            Uint128::new(100),
+        )
+        .unwrap();
+
+        execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            Uint128::new(50),
+            Uint128::new(50),
+        )
+        .unwrap();
+
+        assert_eq!(TOTAL_SHARES.load(deps.as_ref().storage).unwrap(), Uint128::new(150));
+    }
+
+    #[test]
+    fn swap_cannot_fully_drain_a_reserve() {
+        // Reproduces the reviewer's repro: a 1:1 pool with no fee, where a large enough offer
+        // would otherwise compute amount_out == reserve_out and zero out one side of the pool.
+        let mut deps = setup(0);
+        execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            Uint128::new(1),
+            Uint128::new(1),
+        )
+        .unwrap();
+
+        let err = execute_swap(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            "token1".to_string(),
+            Uint128::new(1_000_000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        let pool = POOL_INFO.load(deps.as_ref().storage).unwrap();
+        assert!(!pool.reserve1.is_zero());
+        assert!(!pool.reserve2.is_zero());
+    }
+
+    #[test]
+    fn remove_liquidity_is_proportional() {
+        let mut deps = setup(0);
+        execute_add_liquidity(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            Uint128::new(100),
+            Uint128::new(100),
+        )
+        .unwrap();
+
+        execute_remove_liquidity(deps.as_mut(), mock_info("alice", &[]), Uint128::new(50)).unwrap();
+
+        let pool = POOL_INFO.load(deps.as_ref().storage).unwrap();
+        assert_eq!(pool.reserve1, Uint128::new(50));
+        assert_eq!(pool.reserve2, Uint128::new(50));
+        assert_eq!(TOTAL_SHARES.load(deps.as_ref().storage).unwrap(), Uint128::new(50));
+    }
+}